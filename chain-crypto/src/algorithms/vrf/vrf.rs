@@ -10,8 +10,13 @@ use std::hash::{Hash, Hasher};
 use super::dleq;
 use crate::key::PublicKeyError;
 
-/// VRF Secret Key
-#[derive(Clone)]
+/// VRF Secret Key.
+///
+/// Deliberately not `Clone`: each copy of the secret scalar is another
+/// place it can be forgotten and left un-zeroized, so a secret key
+/// should have a single owner who drops it (triggering the `Drop` impl
+/// below) as soon as it's no longer needed, rather than accumulating
+/// casual clones.
 pub struct SecretKey {
     secret: Scalar,
     public: GroupElement,
@@ -72,7 +77,12 @@ impl SecretKey {
         self.secret.as_bytes()
     }
 
-    /// Serialize the secret key in binary form
+    /// Serialize the secret key in binary form.
+    ///
+    /// The returned array is a plain copy with no zeroizing of its own;
+    /// this key's `Drop` guarantee only covers the bytes owned by `self`.
+    /// Callers that persist or transmit this buffer are responsible for
+    /// clearing it themselves once it's no longer needed.
     pub fn to_bytes(&self) -> [u8; SECRET_SIZE] {
         let mut v = [0u8; SECRET_SIZE];
         v.copy_from_slice(&self.secret.to_bytes());
@@ -157,6 +167,40 @@ impl SecretKey {
     }
 }
 
+/// Overwrite `scalar`'s backing bytes with zeros in place.
+///
+/// SAFETY: `Scalar` is a fixed-size field element with no heap
+/// allocations of its own, so overwriting its bytes is sound. The
+/// volatile write plus fence keep the optimizer from eliding the store
+/// as dead code right before the value is dropped, which a plain
+/// `*scalar = Scalar::zero()` would risk. Kept as the single audited
+/// copy of this primitive in this crate, so any further secret scalar
+/// needing the same treatment calls this rather than re-deriving it.
+#[cfg(feature = "zeroize")]
+fn zeroize_scalar(scalar: &mut Scalar) {
+    unsafe {
+        std::ptr::write_volatile(scalar, std::mem::zeroed());
+    }
+    std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for SecretKey {
+    fn zeroize(&mut self) {
+        zeroize_scalar(&mut self.secret);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for SecretKey {}
+
+#[cfg(feature = "zeroize")]
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
 impl PublicKey {
     pub fn from_bytes(input: &[u8]) -> Result<Self, PublicKeyError> {
         if input.len() != PUBLIC_SIZE {
@@ -191,6 +235,45 @@ impl ProvenOutputSeed {
         dleq::verify(&dleq, &self.dleq_proof)
     }
 
+    /// Verify `k` VRF proofs at once, batching their underlying DLEQ
+    /// checks into two multiscalar multiplications instead of `k`
+    /// independent ones; useful when validating every leader VRF of a
+    /// block at once. Returns `true` only if every proof is valid; fall
+    /// back to [`Self::verify`] on the individual proofs to localize a
+    /// failure.
+    pub fn verify_batch<R: RngCore + CryptoRng>(
+        items: &[(&ProvenOutputSeed, &PublicKey, &[u8])],
+        rng: &mut R,
+    ) -> bool {
+        if items.is_empty() {
+            return true;
+        }
+
+        let generator = GroupElement::generator();
+        let m_points: Vec<GroupElement> = items
+            .iter()
+            .map(|(_, _, input)| GroupElement::from_hash(input))
+            .collect();
+
+        let dleqs: Vec<dleq::Dleq> = items
+            .iter()
+            .zip(&m_points)
+            .map(|((proof, public_key, _), m_point)| dleq::Dleq {
+                g1: &generator,
+                h1: &public_key.0,
+                g2: m_point,
+                h2: &proof.u.0,
+            })
+            .collect();
+        let pairs: Vec<(&dleq::Dleq, &dleq::Proof)> = dleqs
+            .iter()
+            .zip(items.iter())
+            .map(|(d, (proof, _, _))| (d, &proof.dleq_proof))
+            .collect();
+
+        dleq::verify_batch(&pairs, rng)
+    }
+
     pub fn to_buffer(&self, output: &mut [u8]) {
         assert_eq!(output.len(), PROOF_SIZE);
         output[0..32].copy_from_slice(&self.u.0.to_bytes());
@@ -252,7 +335,7 @@ impl OutputSeed {
 
 #[cfg(test)]
 mod tests {
-    use super::SecretKey;
+    use super::{ProvenOutputSeed, PublicKey, SecretKey};
     use rand_core::{OsRng, RngCore};
 
     #[test]
@@ -283,4 +366,51 @@ mod tests {
         assert_eq!(proof.verify(&pk_other, &b1[..]), false);
         assert_eq!(proof.verify(&pk_other, &b2[..]), false);
     }
+
+    #[test]
+    fn batch_verification_accepts_valid_proofs_and_rejects_a_single_bad_one() {
+        let mut csprng: OsRng = OsRng;
+
+        let mut proofs = Vec::new();
+        let mut public_keys = Vec::new();
+        let mut inputs = Vec::new();
+        for i in 0..5u8 {
+            let sk = SecretKey::random(&mut csprng);
+            public_keys.push(sk.public());
+            inputs.push(vec![i; 8]);
+            proofs.push(sk.evaluate_simple(&mut csprng, &inputs[i as usize][..]));
+        }
+
+        let items: Vec<(&ProvenOutputSeed, &PublicKey, &[u8])> = proofs
+            .iter()
+            .zip(&public_keys)
+            .zip(&inputs)
+            .map(|((proof, pk), input)| (proof, pk, input.as_slice()))
+            .collect();
+        assert!(ProvenOutputSeed::verify_batch(&items, &mut csprng));
+
+        let wrong_input = vec![99u8; 8];
+        let mut tampered = items;
+        tampered[0].2 = &wrong_input;
+        assert!(!ProvenOutputSeed::verify_batch(&tampered, &mut csprng));
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn secret_key_bytes_are_cleared_on_drop() {
+        let mut csprng: OsRng = OsRng;
+        let mut sk = SecretKey::random(&mut csprng);
+
+        let ptr = &mut sk.secret as *mut _ as *mut u8;
+        let len = std::mem::size_of_val(&sk.secret);
+        assert!(unsafe { std::slice::from_raw_parts(ptr, len) }
+            .iter()
+            .any(|b| *b != 0));
+
+        drop(sk);
+
+        assert!(unsafe { std::slice::from_raw_parts(ptr, len) }
+            .iter()
+            .all(|b| *b == 0));
+    }
 }
@@ -1,3 +1,8 @@
+//! TODO(manifest): the `zeroize` cfg feature used by the VRF secret key to
+//! scrub its scalar on drop still needs to be declared as a default-on
+//! feature, with `zeroize` as its optional dependency, in this crate's
+//! `Cargo.toml`.
+
 #[macro_use]
 extern crate cfg_if;
 
@@ -0,0 +1,163 @@
+//! Committee member key management.
+//!
+//! A committee member holds the secret key material needed to decrypt its
+//! share of an election tally, and contributes a public key to the joint
+//! election encryption key. [`CommitteeMembersManager`] produces this key
+//! material from a single trusted dealer, which is convenient for testing
+//! and for deployments that already have an out-of-band trusted setup.
+//!
+//! For a dealer-free setup, where no single party ever holds the full
+//! election secret, see [`dkg`].
+
+use crate::cryptography::{Keypair, PublicKey, SecretKey};
+use crate::gang::GroupElement;
+use rand::{CryptoRng, RngCore};
+
+pub mod dkg;
+
+/// Common reference string, a group element independent of the standard
+/// generator, used as the second base of Pedersen commitments.
+#[derive(Clone)]
+pub struct Crs(GroupElement);
+
+impl Crs {
+    /// Derive a CRS from an arbitrary public seed.
+    pub fn from_hash(seed: &[u8]) -> Self {
+        Crs(GroupElement::from_hash(seed))
+    }
+
+    pub(crate) fn generator(&self) -> &GroupElement {
+        &self.0
+    }
+}
+
+/// A committee member's secret key, used to compute its partial decryption
+/// of the tally.
+///
+/// Deliberately not `Clone`: it wraps `cryptography::SecretKey`, which is
+/// itself not `Clone` for the same reason (see its doc comment) — a
+/// committee member's secret key should have one owner, not copies
+/// sitting in buffers the zeroize-on-drop guarantee below can't reach.
+pub struct MemberSecretKey(pub(crate) SecretKey);
+
+/// Overwrite `scalar`'s backing bytes with zeros in place.
+///
+/// SAFETY: `Scalar` is a fixed-size field element with no heap
+/// allocations of its own, so overwriting its bytes is sound. The
+/// volatile write plus fence keep the optimizer from eliding the store
+/// as dead code right before the value is dropped, which a plain
+/// `*scalar = Scalar::zero()` would risk.
+#[cfg(feature = "zeroize")]
+pub(crate) fn zeroize_scalar(scalar: &mut crate::gang::Scalar) {
+    unsafe {
+        std::ptr::write_volatile(scalar, std::mem::zeroed());
+    }
+    std::sync::atomic::compiler_fence(std::sync::atomic::Ordering::SeqCst);
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for MemberSecretKey {
+    fn zeroize(&mut self) {
+        zeroize_scalar(&mut self.0.sk);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for MemberSecretKey {}
+
+#[cfg(feature = "zeroize")]
+impl Drop for MemberSecretKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// A committee member's public key, one summand of the [`ElectionPublicKey`].
+#[derive(Clone)]
+pub struct MemberPublicKey(pub(crate) PublicKey);
+
+impl MemberPublicKey {
+    pub(crate) fn to_inner(&self) -> &PublicKey {
+        &self.0
+    }
+}
+
+/// The election encryption key, the sum of every committee member's public
+/// key. Votes are encrypted against this key; no single committee member
+/// can decrypt a vote alone.
+#[derive(Clone)]
+pub struct ElectionPublicKey(pub(crate) PublicKey);
+
+struct MemberState {
+    // `None` once this member's secret key has been handed out via
+    // `CommitteeMembersManager::member`, so it can only ever move to a
+    // single `CommitteeMember`, never be cloned into a second one.
+    secret_key: Option<MemberSecretKey>,
+    public_key: MemberPublicKey,
+}
+
+/// A single committee member's key material, handed out by a
+/// [`CommitteeMembersManager`].
+pub struct CommitteeMember {
+    secret_key: MemberSecretKey,
+    public_key: MemberPublicKey,
+}
+
+impl CommitteeMember {
+    pub fn secret_key(&self) -> &MemberSecretKey {
+        &self.secret_key
+    }
+
+    pub fn public_key(&self) -> MemberPublicKey {
+        self.public_key.clone()
+    }
+}
+
+/// Generates and distributes committee member key material from a single
+/// dealer.
+pub struct CommitteeMembersManager {
+    members: Vec<MemberState>,
+}
+
+impl CommitteeMembersManager {
+    /// Generate key material for `members_no` committee members.
+    pub fn new<R: RngCore + CryptoRng>(rng: &mut R, members_no: usize) -> Self {
+        let members = (0..members_no)
+            .map(|_| {
+                let keypair = Keypair::generate(rng);
+                MemberState {
+                    secret_key: Some(MemberSecretKey(keypair.secret_key)),
+                    public_key: MemberPublicKey(keypair.public_key),
+                }
+            })
+            .collect();
+        CommitteeMembersManager { members }
+    }
+
+    /// Move out the key material owned by member `owner_index`.
+    ///
+    /// Panics if called twice for the same `owner_index`: a member's
+    /// secret key has exactly one owner, so it is moved out of the
+    /// manager rather than cloned.
+    pub fn member(&mut self, owner_index: usize) -> CommitteeMember {
+        let public_key = self.members[owner_index].public_key.clone();
+        let secret_key = self.members[owner_index]
+            .secret_key
+            .take()
+            .expect("member key material already handed out");
+        CommitteeMember {
+            secret_key,
+            public_key,
+        }
+    }
+
+    /// The joint election public key, the sum of every member's public key.
+    pub fn election_public_key(&self) -> ElectionPublicKey {
+        let pk = self
+            .members
+            .iter()
+            .map(|m| &m.public_key.0.pk)
+            .fold(GroupElement::zero(), |acc, pk| acc + pk);
+        ElectionPublicKey(PublicKey { pk })
+    }
+}
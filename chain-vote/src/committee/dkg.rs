@@ -0,0 +1,478 @@
+//! Dealer-free distributed key generation (DKG) for the committee's
+//! election key.
+//!
+//! Each of the `n` committee members samples a private degree-`t`
+//! polynomial and broadcasts a Feldman commitment to its coefficients,
+//! then privately ships one evaluation of that polynomial to every other
+//! member. A member that receives a share failing Feldman verification
+//! files a [`Complaint`] against the sender; the members surviving every
+//! complaint form the qualified set `Q`, which determines the joint
+//! election key and every member's final secret share. No party ever holds
+//! the full election secret: [`finalize`] gives each member only its own
+//! share of it.
+//!
+//! The protocol is modelled as a three round state machine so that callers
+//! remain free to drive message exchange over whatever transport they
+//! have (broadcast channel, gossip, ...):
+//!
+//! 1. [`Round1::new`] samples the local polynomial and returns the
+//!    [`Round1Broadcast`] to publish and the [`Round1Envelope`]s to send
+//!    privately to each other member.
+//! 2. [`Round1::receive_shares`] consumes every broadcast and the
+//!    envelopes addressed to this member, verifies each share against its
+//!    sender's commitment, and returns the resulting [`Round2State`]
+//!    together with any [`Complaint`]s raised.
+//! 3. [`finalize`] combines a [`Round2State`] for the agreed-upon
+//!    qualified set into the joint [`ElectionPublicKey`] and this member's
+//!    [`MemberKeyShare`].
+
+use super::{ElectionPublicKey, MemberPublicKey};
+use crate::cryptography::PublicKey;
+use crate::gang::{GroupElement, Scalar};
+use rand::{CryptoRng, RngCore};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// 1-indexed identifier of a committee member taking part in the DKG.
+/// Index `0` is never assigned, since it is the natural evaluation point
+/// for the joint secret itself.
+pub type MemberIndex = u32;
+
+/// A degree-`t` polynomial over the scalar field, `f(x) = a0 + a1 x + ... + at x^t`.
+#[derive(Clone)]
+struct Polynomial(Vec<Scalar>);
+
+impl Polynomial {
+    fn random<R: RngCore + CryptoRng>(threshold: usize, rng: &mut R) -> Self {
+        Polynomial((0..=threshold).map(|_| Scalar::random(rng)).collect())
+    }
+
+    /// Evaluate the polynomial at `x` using Horner's method.
+    fn evaluate(&self, x: MemberIndex) -> Scalar {
+        let x = Scalar::from_u64(x as u64);
+        let mut coeffs = self.0.iter().rev();
+        let mut acc = coeffs.next().cloned().unwrap_or_else(Scalar::zero);
+        for coeff in coeffs {
+            acc = &acc * &x + coeff;
+        }
+        acc
+    }
+
+    /// Feldman commitment to every coefficient, `C_k = g^{a_k}`.
+    fn commit(&self) -> Vec<GroupElement> {
+        self.0.iter().map(|a| GroupElement::generator() * a).collect()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Polynomial {
+    fn drop(&mut self) {
+        for coeff in self.0.iter_mut() {
+            super::zeroize_scalar(coeff);
+        }
+    }
+}
+
+/// Verify that `share` is the evaluation at `at` of the polynomial
+/// committed to by `commitment`, i.e. `g^{share} == prod_k commitment[k]^(at^k)`.
+fn verify_feldman_share(commitment: &[GroupElement], at: MemberIndex, share: &Scalar) -> bool {
+    let lhs = GroupElement::generator() * share;
+    let x = Scalar::from_u64(at as u64);
+    let mut x_pow = Scalar::from_u64(1);
+    let mut rhs = GroupElement::zero();
+    for c_k in commitment {
+        let term = c_k * &x_pow;
+        rhs = rhs + &term;
+        x_pow = &x_pow * &x;
+    }
+    lhs == rhs
+}
+
+/// The broadcast message a member sends at the end of round 1: a Feldman
+/// commitment to its private polynomial's coefficients.
+#[derive(Clone)]
+pub struct Round1Broadcast {
+    pub sender: MemberIndex,
+    pub commitment: Vec<GroupElement>,
+}
+
+/// The private message a member sends to one other member at the end of
+/// round 1: that member's evaluation of the sender's private polynomial.
+#[derive(Clone)]
+pub struct Round1Envelope {
+    pub sender: MemberIndex,
+    pub recipient: MemberIndex,
+    pub share: Scalar,
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Round1Envelope {
+    fn drop(&mut self) {
+        super::zeroize_scalar(&mut self.share);
+    }
+}
+
+/// A complaint filed by `accuser` against `accused` because the private
+/// share it received does not match the accused's published commitment.
+/// An accused member failing to rebut a complaint is excluded from the
+/// qualified set.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Complaint {
+    pub accuser: MemberIndex,
+    pub accused: MemberIndex,
+}
+
+/// This member's state after round 1, before the qualified set is agreed
+/// upon.
+pub struct Round1 {
+    index: MemberIndex,
+    members_no: u32,
+    threshold: usize,
+    polynomial: Polynomial,
+}
+
+impl Round1 {
+    /// Start the DKG for member `index` (`1..=members_no`), sampling a
+    /// private degree-`threshold` polynomial and returning the commitment
+    /// to broadcast plus the per-recipient shares to send privately.
+    pub fn new<R: RngCore + CryptoRng>(
+        index: MemberIndex,
+        members_no: u32,
+        threshold: usize,
+        rng: &mut R,
+    ) -> (Self, Round1Broadcast, Vec<Round1Envelope>) {
+        let polynomial = Polynomial::random(threshold, rng);
+        let commitment = polynomial.commit();
+        let envelopes = (1..=members_no)
+            .map(|recipient| Round1Envelope {
+                sender: index,
+                recipient,
+                share: polynomial.evaluate(recipient),
+            })
+            .collect();
+        let broadcast = Round1Broadcast {
+            sender: index,
+            commitment: commitment.clone(),
+        };
+        (
+            Round1 {
+                index,
+                members_no,
+                threshold,
+                polynomial,
+            },
+            broadcast,
+            envelopes,
+        )
+    }
+
+    /// Verify every share addressed to this member against the matching
+    /// broadcast commitment, filing a [`Complaint`] for any mismatch, and
+    /// move on to round 2.
+    ///
+    /// `broadcasts` and `envelopes` must together cover every member in
+    /// `1..=members_no`, including this member's own (trivially valid)
+    /// contribution.
+    pub fn receive_shares(
+        self,
+        broadcasts: &[Round1Broadcast],
+        envelopes: &[Round1Envelope],
+    ) -> (Round2State, Vec<Complaint>) {
+        let commitments: BTreeMap<MemberIndex, &[GroupElement]> = broadcasts
+            .iter()
+            .map(|b| (b.sender, b.commitment.as_slice()))
+            .collect();
+
+        let mut verified_shares = BTreeMap::new();
+        let mut complaints = Vec::new();
+        for envelope in envelopes.iter().filter(|e| e.recipient == self.index) {
+            match commitments.get(&envelope.sender) {
+                Some(commitment) if verify_feldman_share(commitment, self.index, &envelope.share) => {
+                    verified_shares.insert(envelope.sender, envelope.share.clone());
+                }
+                _ => complaints.push(Complaint {
+                    accuser: self.index,
+                    accused: envelope.sender,
+                }),
+            }
+        }
+
+        (
+            Round2State {
+                index: self.index,
+                members_no: self.members_no,
+                threshold: self.threshold,
+                commitments: broadcasts
+                    .iter()
+                    .map(|b| (b.sender, b.commitment.clone()))
+                    .collect(),
+                verified_shares,
+            },
+            complaints,
+        )
+    }
+}
+
+/// This member's state after round 1 shares have been verified, awaiting
+/// agreement on the qualified set `Q` that survived every [`Complaint`].
+pub struct Round2State {
+    index: MemberIndex,
+    members_no: u32,
+    threshold: usize,
+    commitments: BTreeMap<MemberIndex, Vec<GroupElement>>,
+    verified_shares: BTreeMap<MemberIndex, Scalar>,
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for Round2State {
+    fn drop(&mut self) {
+        for share in self.verified_shares.values_mut() {
+            super::zeroize_scalar(share);
+        }
+    }
+}
+
+/// A member's final secret share, wrapped so it cannot be `Copy`d or
+/// cloned into an untracked buffer that would escape the zeroizing
+/// [`Drop`] below (there is deliberately no `Clone` impl).
+pub struct MemberSecretShare(Scalar);
+
+impl MemberSecretShare {
+    pub(crate) fn as_scalar(&self) -> &Scalar {
+        &self.0
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for MemberSecretShare {
+    fn drop(&mut self) {
+        super::zeroize_scalar(&mut self.0);
+    }
+}
+
+/// This member's final key share: its secret contribution to the joint
+/// election key and the corresponding public verification share.
+pub struct MemberKeyShare {
+    pub index: MemberIndex,
+    pub secret_share: MemberSecretShare,
+    pub verification_share: GroupElement,
+}
+
+/// Reasons [`finalize`] could not combine the qualified set `Q` into a key
+/// share. `qualified` is agreed upon externally (e.g. by a higher-level
+/// consensus over every member's complaints), so this node may not locally
+/// agree with it; both variants cover input `finalize` must reject rather
+/// than trust.
+#[derive(Debug, PartialEq, Eq)]
+pub enum FinalizeError {
+    /// `qualified` named `threshold` or fewer *distinct* members. A
+    /// qualified set that small would let an adversary controlling exactly
+    /// those members pin down the joint secret on its own, since the
+    /// degree-`t` sum polynomial is then fully determined without any
+    /// honest member's contribution; repeating an index in `qualified`
+    /// does not count as an additional contributor.
+    NotEnoughQualifiedMembers,
+    /// `qualified` named a member this node has no recorded commitment or
+    /// verified share for — e.g. an index outside `1..=members_no`, or one
+    /// this node filed a [`Complaint`] against.
+    UnknownQualifiedMember(MemberIndex),
+}
+
+/// Combine the contributions of every member in the qualified set `Q` into
+/// the joint election public key and this member's final key share.
+pub fn finalize(
+    state: Round2State,
+    qualified: &[MemberIndex],
+) -> Result<(ElectionPublicKey, MemberKeyShare), FinalizeError> {
+    let qualified: BTreeSet<MemberIndex> = qualified.iter().copied().collect();
+    if qualified.len() <= state.threshold {
+        return Err(FinalizeError::NotEnoughQualifiedMembers);
+    }
+
+    let mut joint_pk = GroupElement::zero();
+    let mut secret_share = Scalar::zero();
+    for i in &qualified {
+        let commitment = state
+            .commitments
+            .get(i)
+            .ok_or(FinalizeError::UnknownQualifiedMember(*i))?;
+        let share = state
+            .verified_shares
+            .get(i)
+            .ok_or(FinalizeError::UnknownQualifiedMember(*i))?;
+        joint_pk = joint_pk + &commitment[0];
+        secret_share = &secret_share + share;
+    }
+
+    let verification_share = GroupElement::generator() * &secret_share;
+
+    Ok((
+        ElectionPublicKey(PublicKey { pk: joint_pk }),
+        MemberKeyShare {
+            index: state.index,
+            secret_share: MemberSecretShare(secret_share),
+            verification_share,
+        },
+    ))
+}
+
+impl MemberKeyShare {
+    /// The public counterpart of this share, `g^{secret_share}`, used by
+    /// other members and verifiers to check this member's partial
+    /// decryptions without learning the secret share itself.
+    pub fn public_key(&self) -> MemberPublicKey {
+        MemberPublicKey(PublicKey {
+            pk: self.verification_share,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand_chacha::ChaCha20Rng;
+    use rand_core::SeedableRng;
+
+    #[test]
+    fn full_dkg_round_trip() {
+        let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+        let members_no = 5u32;
+        let threshold = 2usize;
+
+        let mut broadcasts = Vec::new();
+        let mut all_envelopes = Vec::new();
+        let mut rounds = Vec::new();
+        for index in 1..=members_no {
+            let (round1, broadcast, envelopes) = Round1::new(index, members_no, threshold, &mut rng);
+            rounds.push(round1);
+            broadcasts.push(broadcast);
+            all_envelopes.extend(envelopes);
+        }
+
+        let qualified: Vec<MemberIndex> = (1..=members_no).collect();
+        let mut shares = Vec::new();
+        for round1 in rounds {
+            let (round2, complaints) = round1.receive_shares(&broadcasts, &all_envelopes);
+            assert!(complaints.is_empty());
+            shares.push(finalize(round2, &qualified).unwrap());
+        }
+
+        // every member must agree on the same joint election public key
+        let reference = shares[0].0 .0.pk;
+        for (pk, _) in &shares[1..] {
+            assert!(pk.0.pk == reference);
+        }
+    }
+
+    #[test]
+    fn tampered_share_raises_complaint() {
+        let mut rng = ChaCha20Rng::from_seed([1u8; 32]);
+        let members_no = 3u32;
+        let threshold = 1usize;
+
+        let mut broadcasts = Vec::new();
+        let mut all_envelopes = Vec::new();
+        let mut rounds = Vec::new();
+        for index in 1..=members_no {
+            let (round1, broadcast, envelopes) = Round1::new(index, members_no, threshold, &mut rng);
+            rounds.push(round1);
+            broadcasts.push(broadcast);
+            all_envelopes.extend(envelopes);
+        }
+
+        // corrupt the share member 1 sent to member 2
+        for envelope in all_envelopes.iter_mut() {
+            if envelope.sender == 1 && envelope.recipient == 2 {
+                envelope.share = &envelope.share + &Scalar::from_u64(1);
+            }
+        }
+
+        let victim = rounds.remove(1);
+        let (_, complaints) = victim.receive_shares(&broadcasts, &all_envelopes);
+        assert_eq!(
+            complaints,
+            vec![Complaint {
+                accuser: 2,
+                accused: 1
+            }]
+        );
+    }
+
+    #[test]
+    fn finalize_rejects_a_qualified_set_no_larger_than_threshold() {
+        let mut rng = ChaCha20Rng::from_seed([2u8; 32]);
+        let members_no = 5u32;
+        let threshold = 2usize;
+
+        let mut broadcasts = Vec::new();
+        let mut all_envelopes = Vec::new();
+        let mut rounds = Vec::new();
+        for index in 1..=members_no {
+            let (round1, broadcast, envelopes) = Round1::new(index, members_no, threshold, &mut rng);
+            rounds.push(round1);
+            broadcasts.push(broadcast);
+            all_envelopes.extend(envelopes);
+        }
+
+        // a qualified set no larger than `threshold` would let its members
+        // alone pin down the joint secret
+        let qualified: Vec<MemberIndex> = (1..=members_no).take(threshold).collect();
+        let (round2, _) = rounds.remove(0).receive_shares(&broadcasts, &all_envelopes);
+        assert_eq!(
+            finalize(round2, &qualified).err(),
+            Some(FinalizeError::NotEnoughQualifiedMembers)
+        );
+    }
+
+    #[test]
+    fn finalize_rejects_a_qualified_set_padded_with_repeated_indices() {
+        let mut rng = ChaCha20Rng::from_seed([3u8; 32]);
+        let members_no = 5u32;
+        let threshold = 2usize;
+
+        let mut broadcasts = Vec::new();
+        let mut all_envelopes = Vec::new();
+        let mut rounds = Vec::new();
+        for index in 1..=members_no {
+            let (round1, broadcast, envelopes) = Round1::new(index, members_no, threshold, &mut rng);
+            rounds.push(round1);
+            broadcasts.push(broadcast);
+            all_envelopes.extend(envelopes);
+        }
+
+        // only 2 distinct members (threshold), repeated to a length of 4,
+        // must not slip past the `qualified.len() > threshold` guard
+        let qualified: Vec<MemberIndex> = vec![1, 2, 1, 2];
+        let (round2, _) = rounds.remove(0).receive_shares(&broadcasts, &all_envelopes);
+        assert_eq!(
+            finalize(round2, &qualified).err(),
+            Some(FinalizeError::NotEnoughQualifiedMembers)
+        );
+    }
+
+    #[test]
+    fn finalize_rejects_a_qualified_member_with_no_recorded_share() {
+        let mut rng = ChaCha20Rng::from_seed([4u8; 32]);
+        let members_no = 5u32;
+        let threshold = 2usize;
+
+        let mut broadcasts = Vec::new();
+        let mut all_envelopes = Vec::new();
+        let mut rounds = Vec::new();
+        for index in 1..=members_no {
+            let (round1, broadcast, envelopes) = Round1::new(index, members_no, threshold, &mut rng);
+            rounds.push(round1);
+            broadcasts.push(broadcast);
+            all_envelopes.extend(envelopes);
+        }
+
+        // member 99 never took part in round 1, so this node has no
+        // commitment or verified share recorded for it; an externally
+        // agreed-upon qualified set naming it must not panic
+        let qualified: Vec<MemberIndex> = vec![1, 2, 3, 99];
+        let (round2, _) = rounds.remove(0).receive_shares(&broadcasts, &all_envelopes);
+        assert_eq!(
+            finalize(round2, &qualified).err(),
+            Some(FinalizeError::UnknownQualifiedMember(99))
+        );
+    }
+}
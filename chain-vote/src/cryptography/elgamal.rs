@@ -0,0 +1,74 @@
+//! ElGamal encryption over the `gang` group.
+
+use crate::gang::{GroupElement, Scalar};
+use rand::{CryptoRng, RngCore};
+
+/// An ElGamal secret key.
+///
+/// Wraps its `Scalar` so the key cannot be `Copy`d or cloned into an
+/// untracked buffer that would escape the `Drop`-based zeroizing below.
+pub struct SecretKey {
+    pub(crate) sk: Scalar,
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::Zeroize for SecretKey {
+    fn zeroize(&mut self) {
+        crate::committee::zeroize_scalar(&mut self.sk);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl zeroize::ZeroizeOnDrop for SecretKey {}
+
+#[cfg(feature = "zeroize")]
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+/// An ElGamal public key, `pk = g^sk`.
+#[derive(Clone)]
+pub struct PublicKey {
+    pub(crate) pk: GroupElement,
+}
+
+impl PublicKey {
+    /// Encrypt `message` as `(g^r, message * pk^r)` for a fresh random `r`.
+    pub fn encrypt_point<R: RngCore + CryptoRng>(
+        &self,
+        message: &GroupElement,
+        rng: &mut R,
+    ) -> Ciphertext {
+        let r = Scalar::random(rng);
+        let e1 = GroupElement::generator() * &r;
+        let e2 = message + &(&self.pk * &r);
+        Ciphertext { e1, e2 }
+    }
+}
+
+/// A matching ElGamal secret/public key pair.
+pub struct Keypair {
+    pub secret_key: SecretKey,
+    pub public_key: PublicKey,
+}
+
+impl Keypair {
+    /// Generate a fresh random key pair.
+    pub fn generate<R: RngCore + CryptoRng>(rng: &mut R) -> Self {
+        let sk = Scalar::random(rng);
+        let pk = GroupElement::generator() * &sk;
+        Keypair {
+            secret_key: SecretKey { sk },
+            public_key: PublicKey { pk },
+        }
+    }
+}
+
+/// An ElGamal ciphertext `(e1, e2) = (g^r, m * pk^r)`.
+#[derive(Clone)]
+pub struct Ciphertext {
+    pub(crate) e1: GroupElement,
+    pub(crate) e2: GroupElement,
+}
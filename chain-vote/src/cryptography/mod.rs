@@ -0,0 +1,6 @@
+//! ElGamal encryption over the `gang` group, and the zero-knowledge
+//! proofs built on top of it.
+
+mod elgamal;
+
+pub use elgamal::{Ciphertext, Keypair, PublicKey, SecretKey};
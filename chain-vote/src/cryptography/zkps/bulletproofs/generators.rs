@@ -0,0 +1,66 @@
+//! Pedersen and Bulletproofs generators.
+use crate::gang::GroupElement;
+
+/// The pair of independent generators `(g, h)` used for the Pedersen
+/// value commitments `V = g^v h^gamma`.
+#[derive(Clone)]
+pub struct PedersenGens {
+    pub g: GroupElement,
+    pub h: GroupElement,
+}
+
+impl Default for PedersenGens {
+    fn default() -> Self {
+        PedersenGens {
+            g: GroupElement::generator(),
+            h: GroupElement::from_hash(b"chain-vote.bulletproofs.pedersen.h"),
+        }
+    }
+}
+
+/// `2n` independent generators used by the bit-vector commitments and
+/// the inner-product argument of a range proof: `n` for the `g` vector
+/// and `n` for the `h` vector. When aggregating `m` proofs over
+/// `bit_size`-bit values, `n` is `bit_size * m` rounded up to a power of
+/// two.
+#[derive(Clone)]
+pub struct BulletproofGens {
+    g_vec: Vec<GroupElement>,
+    h_vec: Vec<GroupElement>,
+}
+
+impl BulletproofGens {
+    /// Derive `capacity` independent `(g, h)` generator pairs by hashing
+    /// an index into a domain-separated label, so neither the prover nor
+    /// the verifier need a trusted setup.
+    pub fn new(capacity: usize) -> Self {
+        let g_vec = (0..capacity)
+            .map(|i| GroupElement::from_hash(&Self::label(b"g", i)))
+            .collect();
+        let h_vec = (0..capacity)
+            .map(|i| GroupElement::from_hash(&Self::label(b"h", i)))
+            .collect();
+        BulletproofGens { g_vec, h_vec }
+    }
+
+    fn label(prefix: &[u8], i: usize) -> Vec<u8> {
+        let mut buf = b"chain-vote.bulletproofs.gens.".to_vec();
+        buf.extend_from_slice(prefix);
+        buf.extend_from_slice(&(i as u64).to_le_bytes());
+        buf
+    }
+
+    /// The number of `(g, h)` generator pairs available, i.e. the largest
+    /// `n` that [`Self::take`] can be called with.
+    pub fn capacity(&self) -> usize {
+        self.g_vec.len()
+    }
+
+    /// The first `n` `(g, h)` generators. Panics if `n` exceeds
+    /// [`Self::capacity`]; callers must check that themselves, since this
+    /// is purely an internal slicing helper and has no way to return a
+    /// typed error of its own.
+    pub(crate) fn take(&self, n: usize) -> (&[GroupElement], &[GroupElement]) {
+        (&self.g_vec[..n], &self.h_vec[..n])
+    }
+}
@@ -0,0 +1,150 @@
+//! Logarithmic-size argument that `<a, b> = c` for committed vectors `a`
+//! and `b` of length `n`, proved by recursively halving `(a, b, g, h)`
+//! and folding the generators for `log2(n)` rounds down to a single
+//! remaining element.
+use super::transcript::Transcript;
+use crate::gang::{GroupElement, Scalar};
+
+/// One `(L, R)` pair of group elements per halving round, plus the final
+/// folded scalars `a`, `b`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InnerProductProof {
+    l_vec: Vec<GroupElement>,
+    r_vec: Vec<GroupElement>,
+    a: Scalar,
+    b: Scalar,
+}
+
+impl InnerProductProof {
+    /// Prove `<a, b> = c` for the commitment `P = <a, g> + <b, h> + c*q`,
+    /// recursively halving `(a, b, g, h)` until a single element of each
+    /// remains.
+    pub(super) fn create(
+        transcript: &mut Transcript,
+        q: &GroupElement,
+        mut g: Vec<GroupElement>,
+        mut h: Vec<GroupElement>,
+        mut a: Vec<Scalar>,
+        mut b: Vec<Scalar>,
+    ) -> Self {
+        let mut l_vec = Vec::new();
+        let mut r_vec = Vec::new();
+
+        while a.len() > 1 {
+            let n = a.len() / 2;
+            let (a_lo, a_hi) = a.split_at(n);
+            let (b_lo, b_hi) = b.split_at(n);
+            let (g_lo, g_hi) = g.split_at(n);
+            let (h_lo, h_hi) = h.split_at(n);
+
+            let c_l = inner_product(a_lo, b_hi);
+            let c_r = inner_product(a_hi, b_lo);
+
+            let l = &(multiscalar(g_hi, a_lo) + &multiscalar(h_lo, b_hi)) + &(q * &c_l);
+            let r = &(multiscalar(g_lo, a_hi) + &multiscalar(h_hi, b_lo)) + &(q * &c_r);
+
+            transcript.append_point(b"L", &l);
+            transcript.append_point(b"R", &r);
+            let x = transcript.challenge_scalar(b"x");
+            let x_inv = x.inverse();
+
+            let next_g: Vec<GroupElement> = g_lo
+                .iter()
+                .zip(g_hi)
+                .map(|(lo, hi)| &(lo * &x_inv) + &(hi * &x))
+                .collect();
+            let next_h: Vec<GroupElement> = h_lo
+                .iter()
+                .zip(h_hi)
+                .map(|(lo, hi)| &(lo * &x) + &(hi * &x_inv))
+                .collect();
+            let next_a: Vec<Scalar> = a_lo
+                .iter()
+                .zip(a_hi)
+                .map(|(lo, hi)| &(lo * &x) + &(hi * &x_inv))
+                .collect();
+            let next_b: Vec<Scalar> = b_lo
+                .iter()
+                .zip(b_hi)
+                .map(|(lo, hi)| &(lo * &x_inv) + &(hi * &x))
+                .collect();
+
+            g = next_g;
+            h = next_h;
+            a = next_a;
+            b = next_b;
+
+            l_vec.push(l);
+            r_vec.push(r);
+        }
+
+        InnerProductProof {
+            l_vec,
+            r_vec,
+            a: a[0].clone(),
+            b: b[0].clone(),
+        }
+    }
+
+    /// Verify the argument against generators `g`, `h` and commitment
+    /// `p = <a, g> + <b, h> + <a, b>*q`, replaying the same Fiat-Shamir
+    /// challenges the prover derived.
+    pub(super) fn verify(
+        &self,
+        transcript: &mut Transcript,
+        n: usize,
+        g: &[GroupElement],
+        h: &[GroupElement],
+        q: &GroupElement,
+        p: &GroupElement,
+    ) -> bool {
+        if self.l_vec.len() != self.r_vec.len() || 1usize << self.l_vec.len() != n {
+            return false;
+        }
+
+        let mut challenges = Vec::with_capacity(self.l_vec.len());
+        for (l, r) in self.l_vec.iter().zip(&self.r_vec) {
+            transcript.append_point(b"L", l);
+            transcript.append_point(b"R", r);
+            challenges.push(transcript.challenge_scalar(b"x"));
+        }
+
+        let mut lhs = p.clone();
+        for (x, (l, r)) in challenges.iter().zip(self.l_vec.iter().zip(&self.r_vec)) {
+            let x_sq = x * x;
+            let x_inv_sq = &x.inverse() * &x.inverse();
+            lhs = &(lhs + &(l * &x_sq)) + &(r * &x_inv_sq);
+        }
+
+        let rounds = challenges.len();
+        let mut g_final = GroupElement::zero();
+        let mut h_final = GroupElement::zero();
+        for i in 0..n {
+            let mut s_i = Scalar::from_u64(1);
+            for (round, x) in challenges.iter().enumerate() {
+                let bit_set = (i >> (rounds - 1 - round)) & 1 == 1;
+                s_i = if bit_set { &s_i * x } else { &s_i * &x.inverse() };
+            }
+            g_final = g_final + &(&g[i] * &s_i);
+            h_final = h_final + &(&h[i] * &s_i.inverse());
+        }
+
+        let ab = &self.a * &self.b;
+        let rhs = &(&(g_final * &self.a) + &(h_final * &self.b)) + &(q * &ab);
+
+        lhs == rhs
+    }
+}
+
+fn inner_product(a: &[Scalar], b: &[Scalar]) -> Scalar {
+    a.iter()
+        .zip(b)
+        .fold(Scalar::zero(), |acc, (x, y)| &acc + &(x * y))
+}
+
+fn multiscalar(points: &[GroupElement], scalars: &[Scalar]) -> GroupElement {
+    points
+        .iter()
+        .zip(scalars)
+        .fold(GroupElement::zero(), |acc, (p, s)| acc + &(p * s))
+}
@@ -0,0 +1,16 @@
+//! Bulletproofs aggregated range proofs.
+//!
+//! Proves that each of `m` Pedersen-committed values lies in `[0, 2^n)`
+//! with proof size logarithmic in `n`, so a voter can cast an integer
+//! weight rather than a 0/1 choice while the weight stays small enough
+//! for [`crate::gang::baby_step_giant_step`] to recover the tally, and
+//! without a separate proof per proposal: `m` weights in one ballot share
+//! a single aggregated proof.
+
+mod generators;
+mod inner_product;
+mod range_proof;
+mod transcript;
+
+pub use generators::{BulletproofGens, PedersenGens};
+pub use range_proof::{RangeProof, RangeProofError};
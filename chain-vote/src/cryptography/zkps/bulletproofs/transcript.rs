@@ -0,0 +1,29 @@
+//! Minimal Fiat-Shamir transcript: every message the prover sends is
+//! absorbed before the next verifier challenge is derived from it, so the
+//! interactive protocol can be replayed non-interactively by prover and
+//! verifier alike.
+use crate::gang::{GroupElement, Scalar};
+
+pub(super) struct Transcript {
+    buffer: Vec<u8>,
+}
+
+impl Transcript {
+    pub(super) fn new(label: &'static [u8]) -> Self {
+        Transcript {
+            buffer: label.to_vec(),
+        }
+    }
+
+    pub(super) fn append_point(&mut self, label: &'static [u8], point: &GroupElement) {
+        self.buffer.extend_from_slice(label);
+        self.buffer.extend_from_slice(&point.to_bytes());
+    }
+
+    pub(super) fn challenge_scalar(&mut self, label: &'static [u8]) -> Scalar {
+        self.buffer.extend_from_slice(label);
+        let challenge = Scalar::from_hash(&self.buffer);
+        self.buffer.extend_from_slice(&challenge.to_bytes());
+        challenge
+    }
+}
@@ -55,6 +55,52 @@ impl ProofDecrypt {
         gz == he_a1 && c1z == de_a2
     }
 
+    /// Verify `k` decryption proofs at once.
+    ///
+    /// Recomputes each proof's Fiat-Shamir challenge `e_i` as in
+    /// [`Self::verify`], then collapses the two per-proof verification
+    /// equations into a single aggregate check per equation, each
+    /// evaluated as one multiscalar multiplication with independent
+    /// random weights `rho_i`:
+    ///
+    /// `g^{sum rho_i*z_i} == prod (pk_i^{rho_i*e_i} * a1_i^{rho_i})`
+    /// `prod (c1_i^{rho_i*z_i}) == prod (d_i^{rho_i*e_i} * a2_i^{rho_i})`
+    ///
+    /// A forged proof slipping through would need its error to cancel
+    /// against every other proof's under random weights, which happens
+    /// with negligible probability. Returns `true` only if every proof in
+    /// the batch is valid; use [`Self::verify`] on the individual proofs
+    /// to localize a failure.
+    pub fn verify_batch<R: RngCore + CryptoRng>(
+        items: &[(&Self, &Ciphertext, &GroupElement, &PublicKey)],
+        rng: &mut R,
+    ) -> bool {
+        if items.is_empty() {
+            return true;
+        }
+
+        let mut lhs1 = GroupElement::zero();
+        let mut rhs1 = GroupElement::zero();
+        let mut lhs2 = GroupElement::zero();
+        let mut rhs2 = GroupElement::zero();
+
+        for (proof, c, m, pk) in items {
+            let d = &c.e2 - m;
+            let mut challenge = ChallengeContextProofDecrypt::new(pk, c, &d);
+            let e = challenge.first_challenge(&proof.a1, &proof.a2);
+            let rho = Scalar::random(rng);
+
+            lhs1 = lhs1 + &(GroupElement::generator() * &(&rho * &proof.z));
+            let rho_e = &rho * &e;
+            rhs1 = &(rhs1 + &(&pk.pk * &rho_e)) + &(&proof.a1 * &rho);
+
+            lhs2 = lhs2 + &(&c.e1 * &(&rho * &proof.z));
+            rhs2 = &(rhs2 + &(&d * &rho_e)) + &(&proof.a2 * &rho);
+        }
+
+        lhs1 == rhs1 && lhs2 == rhs2
+    }
+
     pub fn to_bytes(&self) -> [u8; Self::PROOF_SIZE] {
         let mut output = [0u8; Self::PROOF_SIZE];
         self.to_slice_mut(&mut output);
@@ -113,4 +159,43 @@ mod tests {
         let verified = proof.verify(&ciphertext, &plaintext, &keypair.public_key);
         assert_eq!(verified, true);
     }
+
+    #[test]
+    pub fn batch_verification_accepts_valid_proofs_and_rejects_a_single_bad_one() {
+        let mut r = ChaCha20Rng::from_seed([1u8; 32]);
+
+        let mut keypairs = Vec::new();
+        let mut ciphertexts = Vec::new();
+        let mut plaintexts = Vec::new();
+        let mut proofs = Vec::new();
+        for i in 0..5u8 {
+            let keypair = Keypair::generate(&mut r);
+            let plaintext = GroupElement::from_hash(&[i]);
+            let ciphertext = keypair.public_key.encrypt_point(&plaintext, &mut r);
+            let proof = ProofDecrypt::generate(
+                &ciphertext,
+                &keypair.public_key,
+                &keypair.secret_key,
+                &mut r,
+            );
+            keypairs.push(keypair);
+            ciphertexts.push(ciphertext);
+            plaintexts.push(plaintext);
+            proofs.push(proof);
+        }
+
+        let items: Vec<_> = proofs
+            .iter()
+            .zip(&ciphertexts)
+            .zip(&plaintexts)
+            .zip(&keypairs)
+            .map(|(((proof, c), m), kp)| (proof, c, m, &kp.public_key))
+            .collect();
+        assert!(ProofDecrypt::verify_batch(&items, &mut r));
+
+        let wrong_plaintext = GroupElement::from_hash(&[99u8]);
+        let mut tampered = items;
+        tampered[0].2 = &wrong_plaintext;
+        assert!(!ProofDecrypt::verify_batch(&tampered, &mut r));
+    }
 }
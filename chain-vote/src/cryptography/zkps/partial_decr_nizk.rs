@@ -0,0 +1,280 @@
+//! Verifiable threshold decryption.
+//!
+//! [`ProofDecrypt`](super::decr_nizk::ProofDecrypt) proves that a single
+//! key holder decrypted a ciphertext correctly:
+//! `NIZK{(pk, C, M), (sk): M = Dec_sk(C) AND pk = g^sk}`.
+//!
+//! When the election key was produced by a `t`-of-`n` committee, no
+//! single member holds `sk`; instead member `j` holds a share `s_j` with
+//! verification key `pk_j = g^{s_j}`. It publishes a partial decryption
+//! `d_j = C.e1^{s_j}` together with the same Chaum-Pedersen proof,
+//! `NIZK{(pk_j, C, d_j), (s_j): d_j = C.e1^{s_j} AND pk_j = g^{s_j}}`,
+//! establishing `log_g(pk_j) = log_{C.e1}(d_j)` without revealing `s_j`.
+//! Given any `t+1` members' valid partial decryptions, [`combine`]
+//! Lagrange-interpolates them at `x = 0` to recover `C.e1^sk`, from which
+//! the plaintext point follows as `C.e2 - C.e1^sk`.
+#![allow(clippy::many_single_char_names)]
+use super::challenge_context::ChallengeContextProofDecrypt;
+use crate::committee::dkg::{MemberIndex, MemberKeyShare};
+use crate::committee::MemberPublicKey;
+use crate::cryptography::{Ciphertext, PublicKey};
+use crate::gang::{GroupElement, Scalar};
+use rand::{CryptoRng, RngCore};
+use std::collections::BTreeMap;
+
+/// A committee member's partial decryption of a ciphertext, together with
+/// a proof that it was derived from the same secret share as the
+/// member's published verification key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialDecryption {
+    member_index: MemberIndex,
+    d: GroupElement,
+    a1: GroupElement,
+    a2: GroupElement,
+    z: Scalar,
+}
+
+/// Returned by [`combine`] when fewer than `threshold + 1` of the
+/// supplied partial decryptions carry a valid proof.
+#[derive(Debug)]
+pub struct InsufficientShares {
+    pub needed: usize,
+    pub got: usize,
+}
+
+impl PartialDecryption {
+    /// Compute `share`'s partial decryption of `c` and a proof of its
+    /// correctness.
+    pub fn generate<R: RngCore + CryptoRng>(
+        c: &Ciphertext,
+        share: &MemberKeyShare,
+        rng: &mut R,
+    ) -> Self {
+        let pk_j = PublicKey {
+            pk: share.verification_share,
+        };
+        let d = &c.e1 * share.secret_share.as_scalar();
+
+        let w = Scalar::random(rng);
+        let a1 = GroupElement::generator() * &w;
+        let a2 = &c.e1 * &w;
+        let mut challenge = ChallengeContextProofDecrypt::new(&pk_j, c, &d);
+        let e = challenge.first_challenge(&a1, &a2);
+        let z = share.secret_share.as_scalar() * &e + &w;
+
+        PartialDecryption {
+            member_index: share.index,
+            d,
+            a1,
+            a2,
+            z,
+        }
+    }
+
+    /// Verify this partial decryption against the member's public
+    /// verification share `pk_j = g^{s_j}`.
+    pub fn verify(&self, c: &Ciphertext, verification_key: &MemberPublicKey) -> bool {
+        let pk_j = verification_key.to_inner();
+        let mut challenge = ChallengeContextProofDecrypt::new(pk_j, c, &self.d);
+        let e = challenge.first_challenge(&self.a1, &self.a2);
+
+        let gz = GroupElement::generator() * &self.z;
+        let he = &pk_j.pk * &e;
+        let he_a1 = he + &self.a1;
+
+        let c1z = &c.e1 * &self.z;
+        let de = &self.d * &e;
+        let de_a2 = de + &self.a2;
+
+        gz == he_a1 && c1z == de_a2
+    }
+}
+
+/// Lagrange coefficient `lambda_j = prod_{m in at, m != j} m / (m - j)`,
+/// for reconstructing a secret shared at the points in `at`, evaluated at
+/// `x = 0`.
+fn lagrange_at_zero(j: MemberIndex, at: &[MemberIndex]) -> Scalar {
+    let j_scalar = Scalar::from_u64(j as u64);
+    at.iter()
+        .filter(|&&m| m != j)
+        .map(|&m| {
+            let m_scalar = Scalar::from_u64(m as u64);
+            let denom = &m_scalar - &j_scalar;
+            &m_scalar * &denom.inverse()
+        })
+        .fold(Scalar::from_u64(1), |acc, term| &acc * &term)
+}
+
+/// Combine at least `threshold + 1` valid partial decryptions of `c` into
+/// the full decryption `C.e1^sk`. Each entry pairs a [`PartialDecryption`]
+/// with the member's [`MemberPublicKey`] needed to verify it; entries
+/// with an invalid proof are discarded before the threshold is checked.
+/// Entries that repeat a `member_index` already seen are also discarded
+/// before the threshold is checked, since resubmitting the same member's
+/// partial cannot raise the number of *distinct* contributors and, left
+/// unchecked, would count that member's term twice and skew every
+/// coefficient [`lagrange_at_zero`] computes.
+pub fn combine(
+    c: &Ciphertext,
+    threshold: usize,
+    partials: &[(PartialDecryption, MemberPublicKey)],
+) -> Result<GroupElement, InsufficientShares> {
+    let mut valid: BTreeMap<MemberIndex, &PartialDecryption> = BTreeMap::new();
+    for (p, pk) in partials {
+        if p.verify(c, pk) {
+            valid.entry(p.member_index).or_insert(p);
+        }
+    }
+
+    if valid.len() < threshold + 1 {
+        return Err(InsufficientShares {
+            needed: threshold + 1,
+            got: valid.len(),
+        });
+    }
+
+    let indices: Vec<MemberIndex> = valid.keys().copied().collect();
+    Ok(valid
+        .values()
+        .map(|p| {
+            let lambda = lagrange_at_zero(p.member_index, &indices);
+            &p.d * &lambda
+        })
+        .fold(GroupElement::zero(), |acc, term| acc + &term))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::committee::dkg::{finalize, Round1};
+    use rand_chacha::ChaCha20Rng;
+    use rand_core::SeedableRng;
+
+    #[test]
+    pub fn threshold_decryption_recovers_full_decryption() {
+        let mut rng = ChaCha20Rng::from_seed([0u8; 32]);
+        let members_no = 5u32;
+        let threshold = 2usize;
+
+        let mut broadcasts = Vec::new();
+        let mut envelopes = Vec::new();
+        let mut rounds = Vec::new();
+        for index in 1..=members_no {
+            let (round1, broadcast, member_envelopes) =
+                Round1::new(index, members_no, threshold, &mut rng);
+            rounds.push(round1);
+            broadcasts.push(broadcast);
+            envelopes.extend(member_envelopes);
+        }
+
+        let qualified: Vec<MemberIndex> = (1..=members_no).collect();
+        let mut election_pk = None;
+        let mut shares = Vec::new();
+        for round1 in rounds {
+            let (round2, complaints) = round1.receive_shares(&broadcasts, &envelopes);
+            assert!(complaints.is_empty());
+            let (pk, share) = finalize(round2, &qualified).unwrap();
+            election_pk = Some(pk);
+            shares.push(share);
+        }
+        let election_pk = election_pk.unwrap();
+
+        let plaintext = GroupElement::from_hash(&[42u8]);
+        let ciphertext = crate::cryptography::PublicKey {
+            pk: election_pk.0.pk,
+        }
+        .encrypt_point(&plaintext, &mut rng);
+
+        // only a quorum of threshold + 1 members takes part
+        let partials: Vec<_> = shares[..=threshold]
+            .iter()
+            .map(|share| {
+                let partial = PartialDecryption::generate(&ciphertext, share, &mut rng);
+                (partial, share.public_key())
+            })
+            .collect();
+
+        let recovered = combine(&ciphertext, threshold, &partials).unwrap();
+        assert!(ciphertext.e2 - &recovered == plaintext);
+    }
+
+    #[test]
+    pub fn too_few_shares_are_rejected() {
+        let mut rng = ChaCha20Rng::from_seed([1u8; 32]);
+        let members_no = 4u32;
+        let threshold = 2usize;
+
+        let mut broadcasts = Vec::new();
+        let mut envelopes = Vec::new();
+        let mut rounds = Vec::new();
+        for index in 1..=members_no {
+            let (round1, broadcast, member_envelopes) =
+                Round1::new(index, members_no, threshold, &mut rng);
+            rounds.push(round1);
+            broadcasts.push(broadcast);
+            envelopes.extend(member_envelopes);
+        }
+
+        let qualified: Vec<MemberIndex> = (1..=members_no).collect();
+        let (round2, _) = rounds.remove(0).receive_shares(&broadcasts, &envelopes);
+        let (election_pk, share) = finalize(round2, &qualified).unwrap();
+
+        let plaintext = GroupElement::from_hash(&[7u8]);
+        let ciphertext = crate::cryptography::PublicKey {
+            pk: election_pk.0.pk,
+        }
+        .encrypt_point(&plaintext, &mut rng);
+
+        let partial = PartialDecryption::generate(&ciphertext, &share, &mut rng);
+        let partials = vec![(partial, share.public_key())];
+
+        assert!(combine(&ciphertext, threshold, &partials).is_err());
+    }
+
+    #[test]
+    pub fn duplicate_member_index_does_not_pad_the_quorum() {
+        let mut rng = ChaCha20Rng::from_seed([2u8; 32]);
+        let members_no = 5u32;
+        let threshold = 2usize;
+
+        let mut broadcasts = Vec::new();
+        let mut envelopes = Vec::new();
+        let mut rounds = Vec::new();
+        for index in 1..=members_no {
+            let (round1, broadcast, member_envelopes) =
+                Round1::new(index, members_no, threshold, &mut rng);
+            rounds.push(round1);
+            broadcasts.push(broadcast);
+            envelopes.extend(member_envelopes);
+        }
+
+        let qualified: Vec<MemberIndex> = (1..=members_no).collect();
+        let mut election_pk = None;
+        let mut shares = Vec::new();
+        for round1 in rounds {
+            let (round2, _) = round1.receive_shares(&broadcasts, &envelopes);
+            let (pk, share) = finalize(round2, &qualified).unwrap();
+            election_pk = Some(pk);
+            shares.push(share);
+        }
+        let election_pk = election_pk.unwrap();
+
+        let plaintext = GroupElement::from_hash(&[9u8]);
+        let ciphertext = crate::cryptography::PublicKey {
+            pk: election_pk.0.pk,
+        }
+        .encrypt_point(&plaintext, &mut rng);
+
+        // two distinct members' valid partials, but the first one resubmitted
+        // a second time: only 2 distinct contributors, short of threshold + 1
+        let first = PartialDecryption::generate(&ciphertext, &shares[0], &mut rng);
+        let second = PartialDecryption::generate(&ciphertext, &shares[1], &mut rng);
+        let partials = vec![
+            (first.clone(), shares[0].public_key()),
+            (first, shares[0].public_key()),
+            (second, shares[1].public_key()),
+        ];
+
+        assert!(combine(&ciphertext, threshold, &partials).is_err());
+    }
+}
@@ -1,4 +1,9 @@
 //! Chain Vote
+//!
+//! TODO(manifest): the `zeroize` cfg feature used throughout this crate to
+//! scrub secret key material on drop still needs to be declared as a
+//! default-on feature, with `zeroize` as its optional dependency, in this
+//! crate's `Cargo.toml`.
 
 #[macro_use]
 mod macros;